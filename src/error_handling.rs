@@ -0,0 +1,34 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    IOError(std::io::Error),
+    InvalidSchema,
+    MissingSchemaKey(&'static str),
+    NoneASCIICharacters,
+    YamlParsingError(serde_yaml::Error),
+    TomlParsingError(toml::de::Error),
+    JsonParsingError(serde_json::Error),
+    UnsupportedSchemaFormat(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::IOError(e) => write!(f, "IO error: {e}"),
+            Error::InvalidSchema => write!(f, "invalid schema"),
+            Error::MissingSchemaKey(key) => write!(f, "missing schema key: {key}"),
+            Error::NoneASCIICharacters => write!(f, "schema contains non-ASCII characters"),
+            Error::YamlParsingError(e) => write!(f, "failed to parse YAML schema: {e}"),
+            Error::TomlParsingError(e) => write!(f, "failed to parse TOML schema: {e}"),
+            Error::JsonParsingError(e) => write!(f, "failed to parse JSON schema: {e}"),
+            Error::UnsupportedSchemaFormat(path) => {
+                write!(f, "unsupported schema file format: {path}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,2 @@
+pub mod regex_parser;
+pub mod schema_parser;
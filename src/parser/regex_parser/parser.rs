@@ -0,0 +1,26 @@
+use crate::error_handling::Error::InvalidSchema;
+use crate::error_handling::Result;
+use regex_syntax::ast::parse::Parser;
+use regex_syntax::ast::Ast;
+
+pub struct RegexParser {
+    parser: Parser,
+}
+
+impl Default for RegexParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegexParser {
+    pub fn new() -> Self {
+        Self {
+            parser: Parser::new(),
+        }
+    }
+
+    pub fn parse_into_ast(&mut self, pattern: &str) -> Result<Ast> {
+        self.parser.parse(pattern).map_err(|_| InvalidSchema)
+    }
+}
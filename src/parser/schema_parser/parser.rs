@@ -1,5 +1,6 @@
 use crate::error_handling::Error::{
-    IOError, InvalidSchema, MissingSchemaKey, NoneASCIICharacters, YamlParsingError,
+    IOError, InvalidSchema, JsonParsingError, MissingSchemaKey, NoneASCIICharacters,
+    TomlParsingError, UnsupportedSchemaFormat, YamlParsingError,
 };
 use crate::error_handling::Result;
 use crate::parser::regex_parser::parser::RegexParser;
@@ -7,7 +8,9 @@ use regex_syntax::ast::Ast;
 use serde_yaml::Value;
 use std::collections::{HashMap, HashSet};
 use std::io::Read;
+use std::path::Path;
 
+#[derive(Debug)]
 pub struct TimestampSchema {
     regex: String,
     ast: Ast,
@@ -29,6 +32,7 @@ impl TimestampSchema {
     }
 }
 
+#[derive(Debug)]
 pub struct VarSchema {
     pub name: String,
     pub regex: String,
@@ -55,6 +59,7 @@ impl VarSchema {
     }
 }
 
+#[derive(Debug)]
 pub enum Schema {
     Timestamp(TimestampSchema),
     Var(VarSchema),
@@ -69,6 +74,7 @@ impl Schema {
     }
 }
 
+#[derive(Debug)]
 pub struct ParsedSchema {
     pub schemas: Vec<Schema>,
     pub delimiters: HashSet<u8>,
@@ -80,7 +86,7 @@ impl ParsedSchema {
     }
 
     pub fn has_delimiter(&self, delimiter: char) -> bool {
-        if false == delimiter.is_ascii() {
+        if !delimiter.is_ascii() {
             return false;
         }
         self.delimiters.contains(&(delimiter as u8))
@@ -99,16 +105,39 @@ impl ParsedSchema {
         }
     }
 
-    pub fn parse_from_file(yaml_file_path: &str) -> Result<ParsedSchema> {
-        match std::fs::File::open(yaml_file_path) {
+    pub fn parse_from_toml_str(toml_content: &str) -> Result<ParsedSchema> {
+        match Self::load_kv_pairs_from_toml_content(toml_content) {
+            Ok(kv_pairs) => Self::load_from_kv_pairs(kv_pairs),
+            Err(e) => Err(TomlParsingError(e)),
+        }
+    }
+
+    pub fn parse_from_json_str(json_content: &str) -> Result<ParsedSchema> {
+        match Self::load_kv_pairs_from_json_content(json_content) {
+            Ok(kv_pairs) => Self::load_from_kv_pairs(kv_pairs),
+            Err(e) => Err(JsonParsingError(e)),
+        }
+    }
+
+    pub fn parse_from_file(schema_file_path: &str) -> Result<ParsedSchema> {
+        let mut contents = String::new();
+        match std::fs::File::open(schema_file_path) {
             Ok(mut file) => {
-                let mut contents = String::new();
                 if let Err(e) = file.read_to_string(&mut contents) {
                     return Err(IOError(e));
                 }
-                Self::parse_from_str(contents.as_str())
             }
-            Err(e) => Err(IOError(e)),
+            Err(e) => return Err(IOError(e)),
+        }
+
+        match Path::new(schema_file_path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+        {
+            Some("yaml") | Some("yml") => Self::parse_from_str(contents.as_str()),
+            Some("toml") => Self::parse_from_toml_str(contents.as_str()),
+            Some("json") => Self::parse_from_json_str(contents.as_str()),
+            _ => Err(UnsupportedSchemaFormat(schema_file_path.to_string())),
         }
     }
 
@@ -116,16 +145,79 @@ impl ParsedSchema {
         kv_map: &'a HashMap<String, Value>,
         key: &'static str,
     ) -> Result<&'a Value> {
-        kv_map.get(key).ok_or_else(|| MissingSchemaKey(key))
+        kv_map.get(key).ok_or(MissingSchemaKey(key))
     }
 
+    /// Loads the schema's key-value pairs from YAML content into the common `Value`
+    /// representation used by `load_from_kv_pairs`.
     fn load_kv_pairs_from_yaml_content(
         yaml_content: &str,
     ) -> serde_yaml::Result<HashMap<String, Value>> {
-        let kv_map_result: HashMap<String, Value> = serde_yaml::from_str(&yaml_content)?;
+        let kv_map_result: HashMap<String, Value> = serde_yaml::from_str(yaml_content)?;
         Ok(kv_map_result)
     }
 
+    /// Loads the schema's key-value pairs from TOML content, normalizing every scalar (TOML
+    /// parses bare dates/datetimes and numbers into typed values) back to a string so that
+    /// `load_from_kv_pairs` can match on `Value::String` the same way it does for YAML input.
+    fn load_kv_pairs_from_toml_content(
+        toml_content: &str,
+    ) -> std::result::Result<HashMap<String, Value>, toml::de::Error> {
+        let kv_map_result: HashMap<String, toml::Value> = toml::from_str(toml_content)?;
+        Ok(kv_map_result
+            .into_iter()
+            .map(|(key, value)| (key, Self::normalize_toml_value(value)))
+            .collect())
+    }
+
+    /// Loads the schema's key-value pairs from JSON content, normalizing scalars (numbers and
+    /// booleans) back to strings for the same reason as `load_kv_pairs_from_toml_content`.
+    fn load_kv_pairs_from_json_content(
+        json_content: &str,
+    ) -> serde_json::Result<HashMap<String, Value>> {
+        let kv_map_result: HashMap<String, serde_json::Value> = serde_json::from_str(json_content)?;
+        Ok(kv_map_result
+            .into_iter()
+            .map(|(key, value)| (key, Self::normalize_json_value(value)))
+            .collect())
+    }
+
+    fn normalize_toml_value(value: toml::Value) -> Value {
+        match value {
+            toml::Value::String(s) => Value::String(s),
+            toml::Value::Integer(i) => Value::String(i.to_string()),
+            toml::Value::Float(f) => Value::String(f.to_string()),
+            toml::Value::Boolean(b) => Value::String(b.to_string()),
+            toml::Value::Datetime(dt) => Value::String(dt.to_string()),
+            toml::Value::Array(arr) => {
+                Value::Sequence(arr.into_iter().map(Self::normalize_toml_value).collect())
+            }
+            toml::Value::Table(table) => Value::Mapping(
+                table
+                    .into_iter()
+                    .map(|(key, value)| (Value::String(key), Self::normalize_toml_value(value)))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn normalize_json_value(value: serde_json::Value) -> Value {
+        match value {
+            serde_json::Value::Null => Value::Null,
+            serde_json::Value::String(s) => Value::String(s),
+            serde_json::Value::Number(n) => Value::String(n.to_string()),
+            serde_json::Value::Bool(b) => Value::String(b.to_string()),
+            serde_json::Value::Array(arr) => {
+                Value::Sequence(arr.into_iter().map(Self::normalize_json_value).collect())
+            }
+            serde_json::Value::Object(map) => Value::Mapping(
+                map.into_iter()
+                    .map(|(key, value)| (Value::String(key), Self::normalize_json_value(value)))
+                    .collect(),
+            ),
+        }
+    }
+
     fn load_from_kv_pairs(kv_pairs: HashMap<String, Value>) -> Result<Self> {
         let mut delimiters: HashSet<u8> = HashSet::new();
         let mut schemas: Vec<Schema> = Vec::new();
@@ -164,7 +256,7 @@ impl ParsedSchema {
         let delimiter = Self::get_key_value(&kv_pairs, Self::DELIMITER_EKY)?;
         if let Value::String(delimiter_str) = delimiter {
             for c in delimiter_str.chars() {
-                if false == c.is_ascii() {
+                if !c.is_ascii() {
                     return Err(NoneASCIICharacters);
                 }
                 delimiters.insert(c as u8);
@@ -173,10 +265,10 @@ impl ParsedSchema {
             return Err(InvalidSchema);
         }
 
-        Ok((Self {
+        Ok(Self {
             delimiters,
             schemas,
-        }))
+        })
     }
 }
 
@@ -195,10 +287,38 @@ mod tests {
         assert_eq!(parsed_schema.get_schemas().len(), 7);
         for (schema_id, schema) in parsed_schema.get_schemas().iter().enumerate() {
             match schema {
-                Schema::Timestamp(schema) => {
+                Schema::Timestamp(_) => {
+                    assert!(schema_id < 3)
+                }
+                Schema::Var(_) => {
+                    assert!(schema_id >= 3)
+                }
+            }
+        }
+
+        let delimiters: Vec<char> = vec!['\t', '\n', '\r', ':', ',', '!', ';', '%'];
+        for delimiter in delimiters {
+            assert!(parsed_schema.has_delimiter(delimiter));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_example_schema_toml_file() -> Result<()> {
+        let project_root = env!("CARGO_MANIFEST_DIR");
+        let schema_path = std::path::Path::new(project_root)
+            .join("examples")
+            .join("schema.toml");
+        let parsed_schema = ParsedSchema::parse_from_file(schema_path.to_str().unwrap())?;
+
+        assert_eq!(parsed_schema.get_schemas().len(), 7);
+        for (schema_id, schema) in parsed_schema.get_schemas().iter().enumerate() {
+            match schema {
+                Schema::Timestamp(_) => {
                     assert!(schema_id < 3)
                 }
-                Schema::Var(schema) => {
+                Schema::Var(_) => {
                     assert!(schema_id >= 3)
                 }
             }
@@ -211,4 +331,47 @@ mod tests {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_read_example_schema_json_file() -> Result<()> {
+        let project_root = env!("CARGO_MANIFEST_DIR");
+        let schema_path = std::path::Path::new(project_root)
+            .join("examples")
+            .join("schema.json");
+        let parsed_schema = ParsedSchema::parse_from_file(schema_path.to_str().unwrap())?;
+
+        assert_eq!(parsed_schema.get_schemas().len(), 7);
+        for (schema_id, schema) in parsed_schema.get_schemas().iter().enumerate() {
+            match schema {
+                Schema::Timestamp(_) => {
+                    assert!(schema_id < 3)
+                }
+                Schema::Var(_) => {
+                    assert!(schema_id >= 3)
+                }
+            }
+        }
+
+        let delimiters: Vec<char> = vec!['\t', '\n', '\r', ':', ',', '!', ';', '%'];
+        for delimiter in delimiters {
+            assert!(parsed_schema.has_delimiter(delimiter));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_from_file_rejects_unsupported_extension() {
+        let project_root = env!("CARGO_MANIFEST_DIR");
+        let schema_path = std::path::Path::new(project_root)
+            .join("examples")
+            .join("schema.txt");
+
+        let err = ParsedSchema::parse_from_file(schema_path.to_str().unwrap()).unwrap_err();
+        assert!(matches!(
+            err,
+            crate::error_handling::Error::UnsupportedSchemaFormat(path)
+                if path == schema_path.to_str().unwrap()
+        ));
+    }
+}